@@ -0,0 +1,368 @@
+use ark_bls12_381::Fr;
+use ark_ff::PrimeField;
+use ark_relations::r1cs::{
+    ConstraintMatrices, ConstraintSynthesizer, ConstraintSystemRef, LinearCombination,
+    SynthesisError, Variable,
+};
+use std::io::{self, Read};
+
+// One linear combination as a list of (wire index, coefficient) terms.
+type Lc = Vec<(usize, Fr)>;
+
+/// A circom-compiled constraint system, parsed straight out of a `.r1cs`
+/// binary. Wire indices follow circom's convention: index 0 is the constant
+/// "1" wire, followed by the public outputs, public inputs, and finally the
+/// private wires.
+#[derive(Clone, Debug)]
+pub struct CircomR1CS {
+    /// number of public wires (outputs + inputs), excluding the constant wire
+    pub num_public: usize,
+    /// total number of wires including the constant wire at index 0
+    pub num_wires: usize,
+    /// each constraint is a triple of linear combinations (A, B, C)
+    pub constraints: Vec<(Lc, Lc, Lc)>,
+}
+
+impl CircomR1CS {
+    /// Number of instance (public) variables, counting the constant "1" wire.
+    pub fn num_instance_variables(&self) -> usize {
+        1 + self.num_public
+    }
+
+    /// Build an `ark_relations` [`ConstraintMatrices`] straight from the parsed
+    /// sparse A/B/C lists, bypassing gadget synthesis entirely. Circom wire
+    /// indices already match the `z`-vector column order (constant "1" at 0,
+    /// then public wires, then private), so the terms drop in unchanged.
+    pub fn to_matrices(&self) -> ConstraintMatrices<Fr> {
+        let to_matrix = |select: fn(&(Lc, Lc, Lc)) -> &Lc| -> Vec<Vec<(Fr, usize)>> {
+            self.constraints
+                .iter()
+                .map(|triple| select(triple).iter().map(|(w, c)| (*c, *w)).collect())
+                .collect()
+        };
+        let a = to_matrix(|t| &t.0);
+        let b = to_matrix(|t| &t.1);
+        let c = to_matrix(|t| &t.2);
+
+        let count_nz = |m: &[Vec<(Fr, usize)>]| m.iter().map(|row| row.len()).sum();
+        let num_instance_variables = self.num_instance_variables();
+
+        ConstraintMatrices {
+            num_instance_variables,
+            num_witness_variables: self.num_wires - num_instance_variables,
+            num_constraints: self.constraints.len(),
+            a_num_non_zero: count_nz(&a),
+            b_num_non_zero: count_nz(&b),
+            c_num_non_zero: count_nz(&c),
+            a,
+            b,
+            c,
+        }
+    }
+}
+
+fn read_u32<R: Read>(r: &mut R) -> io::Result<u32> {
+    let mut buf = [0u8; 4];
+    r.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_u64<R: Read>(r: &mut R) -> io::Result<u64> {
+    let mut buf = [0u8; 8];
+    r.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+// field elements are stored little-endian in `fs` bytes
+fn read_field<R: Read>(r: &mut R, fs: usize) -> io::Result<Fr> {
+    let mut buf = vec![0u8; fs];
+    r.read_exact(&mut buf)?;
+    Ok(Fr::from_le_bytes_mod_order(&buf))
+}
+
+fn read_lc<R: Read>(r: &mut R, fs: usize) -> io::Result<Lc> {
+    let n = read_u32(r)? as usize;
+    let mut terms = Vec::with_capacity(n);
+    for _ in 0..n {
+        let wire = read_u32(r)? as usize;
+        let coeff = read_field(r, fs)?;
+        terms.push((wire, coeff));
+    }
+    Ok(terms)
+}
+
+/// Parse a circom `.r1cs` binary from any reader.
+pub fn read_r1cs<R: Read>(mut r: R) -> io::Result<CircomR1CS> {
+    let mut magic = [0u8; 4];
+    r.read_exact(&mut magic)?;
+    if &magic != b"r1cs" {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "bad r1cs magic"));
+    }
+    let _version = read_u32(&mut r)?;
+    let num_sections = read_u32(&mut r)?;
+
+    let mut fs = 32usize;
+    let mut num_wires = 0usize;
+    let mut num_public = 0usize;
+    let mut num_constraints = 0usize;
+    let mut constraints = Vec::new();
+
+    for _ in 0..num_sections {
+        let section_type = read_u32(&mut r)?;
+        let section_size = read_u64(&mut r)?;
+        match section_type {
+            // header
+            1 => {
+                fs = read_u32(&mut r)? as usize;
+                let mut prime = vec![0u8; fs];
+                r.read_exact(&mut prime)?;
+                num_wires = read_u32(&mut r)? as usize;
+                let n_pub_out = read_u32(&mut r)? as usize;
+                let n_pub_in = read_u32(&mut r)? as usize;
+                let _n_prv_in = read_u32(&mut r)?;
+                let _n_labels = read_u64(&mut r)?;
+                num_constraints = read_u32(&mut r)? as usize;
+                num_public = n_pub_out + n_pub_in;
+            }
+            // constraints
+            2 => {
+                for _ in 0..num_constraints {
+                    let a = read_lc(&mut r, fs)?;
+                    let b = read_lc(&mut r, fs)?;
+                    let c = read_lc(&mut r, fs)?;
+                    constraints.push((a, b, c));
+                }
+            }
+            // anything else (e.g. wire-to-label map) is skipped
+            _ => {
+                let mut skip = vec![0u8; section_size as usize];
+                r.read_exact(&mut skip)?;
+            }
+        }
+    }
+
+    Ok(CircomR1CS {
+        num_public,
+        num_wires,
+        constraints,
+    })
+}
+
+/// Parse a circom `.wtns` witness binary into the full `z` vector (wire 0 is
+/// the constant "1").
+pub fn read_wtns<R: Read>(mut r: R) -> io::Result<Vec<Fr>> {
+    let mut magic = [0u8; 4];
+    r.read_exact(&mut magic)?;
+    if &magic != b"wtns" {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "bad wtns magic"));
+    }
+    let _version = read_u32(&mut r)?;
+    let num_sections = read_u32(&mut r)?;
+
+    let mut fs = 32usize;
+    let mut num_witness = 0usize;
+    let mut witness = Vec::new();
+
+    for _ in 0..num_sections {
+        let section_type = read_u32(&mut r)?;
+        let section_size = read_u64(&mut r)?;
+        match section_type {
+            1 => {
+                fs = read_u32(&mut r)? as usize;
+                let mut prime = vec![0u8; fs];
+                r.read_exact(&mut prime)?;
+                num_witness = read_u32(&mut r)? as usize;
+            }
+            2 => {
+                witness = Vec::with_capacity(num_witness);
+                for _ in 0..num_witness {
+                    witness.push(read_field(&mut r, fs)?);
+                }
+            }
+            _ => {
+                let mut skip = vec![0u8; section_size as usize];
+                r.read_exact(&mut skip)?;
+            }
+        }
+    }
+
+    Ok(witness)
+}
+
+/// A circom-imported circuit ready to hand to Groth16. It carries the parsed
+/// constraints and the witness, and replays them into a fresh constraint
+/// system so the rest of the pipeline (setup/prove/verify) is unchanged.
+#[derive(Clone, Debug)]
+pub struct CircomCircuit {
+    pub r1cs: CircomR1CS,
+    pub witness: Vec<Fr>,
+}
+
+impl CircomCircuit {
+    /// The public-input slice Groth16 verifies against: the public wires that
+    /// follow the constant "1" wire, in circom order.
+    pub fn public_inputs(&self) -> Vec<Fr> {
+        self.witness[1..=self.r1cs.num_public].to_vec()
+    }
+}
+
+impl ConstraintSynthesizer<Fr> for CircomCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        // arkworks' Groth16 entry points only accept a synthesizer, so to feed
+        // our precalculated matrices we build them directly and then install
+        // each row verbatim — no gadget arithmetic is performed here.
+        let matrices = self.r1cs.to_matrices();
+
+        // wire 0 is the constant "1"; the next `num_public` wires are public
+        // inputs, and the rest are private witnesses.
+        let mut vars: Vec<Variable> = Vec::with_capacity(self.r1cs.num_wires);
+        vars.push(Variable::One);
+        for i in 1..self.r1cs.num_wires {
+            let value = self.witness[i];
+            let var = if i < matrices.num_instance_variables {
+                cs.new_input_variable(|| Ok(value))?
+            } else {
+                cs.new_witness_variable(|| Ok(value))?
+            };
+            vars.push(var);
+        }
+
+        let make_lc = |row: &[(Fr, usize)]| {
+            let mut lc = LinearCombination::zero();
+            for (coeff, wire) in row {
+                lc += (*coeff, vars[*wire]);
+            }
+            lc
+        };
+
+        for i in 0..matrices.num_constraints {
+            cs.enforce_constraint(
+                make_lc(&matrices.a[i]),
+                make_lc(&matrices.b[i]),
+                make_lc(&matrices.c[i]),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_381::Bls12_381;
+    use ark_groth16::Groth16;
+    use ark_relations::r1cs::ConstraintSystem;
+    use ark_snark::SNARK;
+    use ark_std::rand::thread_rng;
+    use std::io::Cursor;
+
+    // encode a field element as `fs` little-endian bytes
+    fn fe_bytes(v: u64, fs: usize) -> Vec<u8> {
+        let mut buf = vec![0u8; fs];
+        buf[..8].copy_from_slice(&v.to_le_bytes());
+        buf
+    }
+
+    fn lc_bytes(terms: &[(u32, u64)], fs: usize) -> Vec<u8> {
+        let mut out = (terms.len() as u32).to_le_bytes().to_vec();
+        for (wire, coeff) in terms {
+            out.extend_from_slice(&wire.to_le_bytes());
+            out.extend_from_slice(&fe_bytes(*coeff, fs));
+        }
+        out
+    }
+
+    // A tiny circuit: one public input `y`, one private witness `x`, with the
+    // single constraint x * x == y. Wires: [1, y, x].
+    fn tiny_r1cs(fs: usize) -> Vec<u8> {
+        let mut header = Vec::new();
+        header.extend_from_slice(&(fs as u32).to_le_bytes());
+        header.extend_from_slice(&fe_bytes(0, fs)); // prime (unused in parse)
+        header.extend_from_slice(&3u32.to_le_bytes()); // num wires
+        header.extend_from_slice(&0u32.to_le_bytes()); // pub out
+        header.extend_from_slice(&1u32.to_le_bytes()); // pub in
+        header.extend_from_slice(&1u32.to_le_bytes()); // prv in
+        header.extend_from_slice(&0u64.to_le_bytes()); // num labels
+        header.extend_from_slice(&1u32.to_le_bytes()); // num constraints
+
+        // A = x (wire 2), B = x (wire 2), C = y (wire 1)
+        let mut constraints = Vec::new();
+        constraints.extend(lc_bytes(&[(2, 1)], fs));
+        constraints.extend(lc_bytes(&[(2, 1)], fs));
+        constraints.extend(lc_bytes(&[(1, 1)], fs));
+
+        let mut out = Vec::new();
+        out.extend_from_slice(b"r1cs");
+        out.extend_from_slice(&1u32.to_le_bytes()); // version
+        out.extend_from_slice(&2u32.to_le_bytes()); // num sections
+        out.extend_from_slice(&1u32.to_le_bytes()); // section type: header
+        out.extend_from_slice(&(header.len() as u64).to_le_bytes());
+        out.extend(header);
+        out.extend_from_slice(&2u32.to_le_bytes()); // section type: constraints
+        out.extend_from_slice(&(constraints.len() as u64).to_le_bytes());
+        out.extend(constraints);
+        out
+    }
+
+    // witness for x = 3, y = 9: [1, 9, 3]
+    fn tiny_wtns(fs: usize) -> Vec<u8> {
+        let mut header = Vec::new();
+        header.extend_from_slice(&(fs as u32).to_le_bytes());
+        header.extend_from_slice(&fe_bytes(0, fs));
+        header.extend_from_slice(&3u32.to_le_bytes()); // num witness
+
+        let mut data = Vec::new();
+        for v in [1u64, 9, 3] {
+            data.extend(fe_bytes(v, fs));
+        }
+
+        let mut out = Vec::new();
+        out.extend_from_slice(b"wtns");
+        out.extend_from_slice(&2u32.to_le_bytes());
+        out.extend_from_slice(&2u32.to_le_bytes()); // num sections
+        out.extend_from_slice(&1u32.to_le_bytes());
+        out.extend_from_slice(&(header.len() as u64).to_le_bytes());
+        out.extend(header);
+        out.extend_from_slice(&2u32.to_le_bytes());
+        out.extend_from_slice(&(data.len() as u64).to_le_bytes());
+        out.extend(data);
+        out
+    }
+
+    #[test]
+    fn imported_circuit_matches_and_verifies() {
+        let fs = 32;
+        let r1cs = read_r1cs(Cursor::new(tiny_r1cs(fs))).unwrap();
+        let witness = read_wtns(Cursor::new(tiny_wtns(fs))).unwrap();
+
+        assert_eq!(r1cs.num_public, 1);
+        assert_eq!(r1cs.num_wires, 3);
+        assert_eq!(r1cs.constraints.len(), 1);
+        assert_eq!(witness, vec![Fr::from(1u64), Fr::from(9u64), Fr::from(3u64)]);
+
+        // the directly-built matrices match the hand-written x*x == y circuit:
+        // A = x (wire 2), B = x (wire 2), C = y (wire 1)
+        let matrices = r1cs.to_matrices();
+        assert_eq!(matrices.num_instance_variables, 2);
+        assert_eq!(matrices.num_witness_variables, 1);
+        assert_eq!(matrices.num_constraints, 1);
+        assert_eq!(matrices.a, vec![vec![(Fr::from(1u64), 2)]]);
+        assert_eq!(matrices.b, vec![vec![(Fr::from(1u64), 2)]]);
+        assert_eq!(matrices.c, vec![vec![(Fr::from(1u64), 1)]]);
+
+        let circuit = CircomCircuit { r1cs, witness };
+        assert_eq!(circuit.public_inputs(), vec![Fr::from(9u64)]);
+
+        let cs = ConstraintSystem::new_ref();
+        circuit.clone().generate_constraints(cs.clone()).unwrap();
+        assert!(cs.is_satisfied().unwrap());
+
+        // the imported matrices produce a verifying Groth16 proof
+        let mut rng = thread_rng();
+        let public_input = circuit.public_inputs();
+        let (pk, vk) =
+            Groth16::<Bls12_381>::circuit_specific_setup(circuit.clone(), &mut rng).unwrap();
+        let proof = Groth16::<Bls12_381>::prove(&pk, circuit, &mut rng).unwrap();
+        assert!(Groth16::<Bls12_381>::verify(&vk, &public_input, &proof).unwrap());
+    }
+}
@@ -0,0 +1,182 @@
+use ark_bls12_381::Fr;
+use ark_ff::Field;
+
+// Sparse matrix rows in the same layout that `ConstraintMatrices` hands us:
+// each row is a list of (value, column index) pairs, where the column index
+// lines up with the position in the z vector.
+pub type SparseMatrix = Vec<Vec<(Fr, usize)>>;
+
+// multiply a sparse matrix by the dense z vector, giving one field element per row
+fn mat_vec(matrix: &SparseMatrix, z: &[Fr]) -> Vec<Fr> {
+    matrix
+        .iter()
+        .map(|row| row.iter().fold(Fr::ZERO, |acc, (val, idx)| acc + *val * z[*idx]))
+        .collect()
+}
+
+// elementwise (Hadamard) product of two equal-length vectors
+fn hadamard(lhs: &[Fr], rhs: &[Fr]) -> Vec<Fr> {
+    lhs.iter().zip(rhs.iter()).map(|(a, b)| *a * *b).collect()
+}
+
+/// Plain R1CS built from the extracted A/B/C matrices. The relation it checks
+/// is the textbook one: `hadamard(Az, Bz) == Cz`.
+#[derive(Clone, Debug)]
+pub struct R1CS {
+    pub a: SparseMatrix,
+    pub b: SparseMatrix,
+    pub c: SparseMatrix,
+}
+
+impl R1CS {
+    pub fn new(a: SparseMatrix, b: SparseMatrix, c: SparseMatrix) -> Self {
+        Self { a, b, c }
+    }
+
+    /// Assert `hadamard(Az, Bz) == Cz` for the given assignment `z`.
+    pub fn check_relation(&self, z: &[Fr]) -> bool {
+        let az = mat_vec(&self.a, z);
+        let bz = mat_vec(&self.b, z);
+        let cz = mat_vec(&self.c, z);
+        hadamard(&az, &bz) == cz
+    }
+
+    /// Relax this R1CS into the Nova form by attaching a zero error vector and
+    /// scalar `u = 1`. A satisfying `z` for the plain relation is also a
+    /// satisfying instance of the relaxed relation (`E = 0`, `u = 1`).
+    pub fn relax(&self, z: Vec<Fr>) -> RelaxedR1CS {
+        let num_rows = self.a.len();
+        RelaxedR1CS {
+            a: self.a.clone(),
+            b: self.b.clone(),
+            c: self.c.clone(),
+            z,
+            e: vec![Fr::ZERO; num_rows],
+            u: Fr::ONE,
+        }
+    }
+}
+
+/// Relaxed R1CS instance-witness pair, as used by Nova's folding scheme. The
+/// relation becomes `hadamard(Az, Bz) == u*Cz + E`, which the plain relation
+/// recovers at `u = 1`, `E = 0`.
+#[derive(Clone, Debug)]
+pub struct RelaxedR1CS {
+    pub a: SparseMatrix,
+    pub b: SparseMatrix,
+    pub c: SparseMatrix,
+    pub z: Vec<Fr>,
+    pub e: Vec<Fr>,
+    pub u: Fr,
+}
+
+impl RelaxedR1CS {
+    /// Assert `hadamard(Az, Bz) == u*Cz + E`.
+    pub fn check_relation(&self) -> bool {
+        let az = mat_vec(&self.a, &self.z);
+        let bz = mat_vec(&self.b, &self.z);
+        let cz = mat_vec(&self.c, &self.z);
+        let lhs = hadamard(&az, &bz);
+        let rhs: Vec<Fr> = cz
+            .iter()
+            .zip(self.e.iter())
+            .map(|(c, e)| self.u * *c + *e)
+            .collect();
+        lhs == rhs
+    }
+
+    /// Non-interactive folding step (NIFS). Given two relaxed instances over
+    /// the same matrices and a challenge `r`, produce the folded instance
+    ///
+    /// ```text
+    /// T = Az1∘Bz2 + Az2∘Bz1 - u1*Cz2 - u2*Cz1
+    /// z = z1 + r*z2
+    /// u = u1 + r*u2
+    /// E = E1 + r*T + r^2*E2
+    /// ```
+    pub fn fold(&self, other: &RelaxedR1CS, r: Fr) -> RelaxedR1CS {
+        let az1 = mat_vec(&self.a, &self.z);
+        let bz1 = mat_vec(&self.b, &self.z);
+        let cz1 = mat_vec(&self.c, &self.z);
+        let az2 = mat_vec(&other.a, &other.z);
+        let bz2 = mat_vec(&other.b, &other.z);
+        let cz2 = mat_vec(&other.c, &other.z);
+
+        // cross term T
+        let az1_bz2 = hadamard(&az1, &bz2);
+        let az2_bz1 = hadamard(&az2, &bz1);
+        let t: Vec<Fr> = (0..az1_bz2.len())
+            .map(|i| az1_bz2[i] + az2_bz1[i] - self.u * cz2[i] - other.u * cz1[i])
+            .collect();
+
+        // z = z1 + r*z2
+        let z: Vec<Fr> = self
+            .z
+            .iter()
+            .zip(other.z.iter())
+            .map(|(a, b)| *a + r * *b)
+            .collect();
+
+        // u = u1 + r*u2
+        let u = self.u + r * other.u;
+
+        // E = E1 + r*T + r^2*E2
+        let r_sq = r * r;
+        let e: Vec<Fr> = (0..self.e.len())
+            .map(|i| self.e[i] + r * t[i] + r_sq * other.e[i])
+            .collect();
+
+        RelaxedR1CS {
+            a: self.a.clone(),
+            b: self.b.clone(),
+            c: self.c.clone(),
+            z,
+            e,
+            u,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CubeCircuit;
+    use ark_ff::PrimeField;
+    use ark_relations::r1cs::{ConstraintMatrices, ConstraintSynthesizer, ConstraintSystem};
+
+    // synthesize a CubeCircuit for the given x and return its matrices and z vector
+    fn extract(x: Fr) -> (R1CS, Vec<Fr>) {
+        let y = x * x * x + x + Fr::from(5u64);
+        let circuit = CubeCircuit { x, y };
+
+        let cs = ConstraintSystem::new_ref();
+        circuit.generate_constraints(cs.clone()).unwrap();
+        assert!(cs.is_satisfied().unwrap());
+
+        let mut z = vec![];
+        z.extend(cs.borrow().unwrap().instance_assignment.clone());
+        z.extend(cs.borrow().unwrap().witness_assignment.clone());
+
+        cs.inline_all_lcs();
+        let matrices: ConstraintMatrices<Fr> = cs.borrow().unwrap().to_matrices().unwrap();
+        (R1CS::new(matrices.a, matrices.b, matrices.c), z)
+    }
+
+    #[test]
+    fn fold_two_satisfying_cube_instances() {
+        let (r1cs, z1) = extract(Fr::from(3u64));
+        let (_, z2) = extract(Fr::from(7u64));
+
+        assert!(r1cs.check_relation(&z1));
+        assert!(r1cs.check_relation(&z2));
+
+        let relaxed1 = r1cs.relax(z1);
+        let relaxed2 = r1cs.relax(z2);
+        assert!(relaxed1.check_relation());
+        assert!(relaxed2.check_relation());
+
+        let r = Fr::from(42u64);
+        let folded = relaxed1.fold(&relaxed2, r);
+        assert!(folded.check_relation());
+    }
+}
@@ -0,0 +1,108 @@
+use ark_ff::PrimeField;
+use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, fields::fp::FpVar};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+/// Reusable frontend trait describing one step of a state transition
+/// `z_{i+1} = f(z_i)`. Implementors emit the constraints for a single step and
+/// return the next state, which lets the same frontend drive a one-shot proof
+/// or an IVC-style chain of folds.
+pub trait FCircuit<F: PrimeField>: Clone {
+    /// Width of the state vector `z_i`.
+    fn state_len(&self) -> usize;
+
+    /// Allocate the constraints for one step and return the next state
+    /// `z_{i+1}` as a vector of `FpVar`s.
+    fn generate_step_constraints(
+        &self,
+        cs: ConstraintSystemRef<F>,
+        z_i: Vec<FpVar<F>>,
+    ) -> Result<Vec<FpVar<F>>, SynthesisError>;
+}
+
+/// The cube relation recast as a state transition `z_{i+1} = z_i^3 + z_i + 5`.
+#[derive(Clone, Debug, Default)]
+pub struct CubicFCircuit;
+
+impl<F: PrimeField> FCircuit<F> for CubicFCircuit {
+    fn state_len(&self) -> usize {
+        1
+    }
+
+    fn generate_step_constraints(
+        &self,
+        _cs: ConstraintSystemRef<F>,
+        z_i: Vec<FpVar<F>>,
+    ) -> Result<Vec<FpVar<F>>, SynthesisError> {
+        let x = &z_i[0];
+        // constrain x^2 = x * x, then x^3 = x^2 * x
+        let x_squared = x * x;
+        let x_cubed = x_squared * x;
+        let next = &x_cubed + x + FpVar::Constant(F::from(5u64));
+        Ok(vec![next])
+    }
+}
+
+/// A sizing knob for benchmarks: emits exactly `n` multiplication constraints
+/// by repeatedly squaring the first state element. The next state is the final
+/// squared value, so chaining still type-checks against `state_len() == 1`.
+#[derive(Clone, Debug)]
+pub struct CustomFCircuit {
+    pub n: usize,
+}
+
+impl CustomFCircuit {
+    pub fn new(n: usize) -> Self {
+        Self { n }
+    }
+}
+
+impl<F: PrimeField> FCircuit<F> for CustomFCircuit {
+    fn state_len(&self) -> usize {
+        1
+    }
+
+    fn generate_step_constraints(
+        &self,
+        _cs: ConstraintSystemRef<F>,
+        z_i: Vec<FpVar<F>>,
+    ) -> Result<Vec<FpVar<F>>, SynthesisError> {
+        let mut acc = z_i[0].clone();
+        // each squaring is one R1CS multiplication constraint
+        for _ in 0..self.n {
+            acc = &acc * &acc;
+        }
+        Ok(vec![acc])
+    }
+}
+
+/// Wraps an [`FCircuit`] into a one-step `ConstraintSynthesizer`: the initial
+/// state `z_0` and the claimed next state `z_1` are allocated as public
+/// inputs, and the step output is enforced to equal `z_1`. Handy for driving
+/// Groth16 setup/prove/verify over an arbitrary frontend.
+#[derive(Clone, Debug)]
+pub struct StepCircuit<F: PrimeField, FC: FCircuit<F>> {
+    pub f: FC,
+    pub z_0: Vec<F>,
+    pub z_1: Vec<F>,
+}
+
+impl<F: PrimeField, FC: FCircuit<F>> ConstraintSynthesizer<F> for StepCircuit<F, FC> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let z_0: Vec<FpVar<F>> = self
+            .z_0
+            .iter()
+            .map(|v| FpVar::new_input(cs.clone(), || Ok(*v)))
+            .collect::<Result<_, _>>()?;
+        let z_1: Vec<FpVar<F>> = self
+            .z_1
+            .iter()
+            .map(|v| FpVar::new_input(cs.clone(), || Ok(*v)))
+            .collect::<Result<_, _>>()?;
+
+        let next = self.f.generate_step_constraints(cs.clone(), z_0)?;
+        for (out, claimed) in next.iter().zip(z_1.iter()) {
+            out.enforce_equal(claimed)?;
+        }
+        Ok(())
+    }
+}
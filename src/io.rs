@@ -0,0 +1,60 @@
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+// map an ark-serialize error onto std::io::Error so callers get one error type
+fn ser_err(e: ark_serialize::SerializationError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e.to_string())
+}
+
+/// Serialize any `CanonicalSerialize` value to `path` in compressed form.
+pub fn write_to_file<T: CanonicalSerialize, P: AsRef<Path>>(value: &T, path: P) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    value.serialize_compressed(&mut file).map_err(ser_err)
+}
+
+/// Deserialize a `CanonicalDeserialize` value from `path` written by
+/// [`write_to_file`].
+pub fn read_from_file<T: CanonicalDeserialize, P: AsRef<Path>>(path: P) -> io::Result<T> {
+    let mut file = File::open(path)?;
+    T::deserialize_compressed(&mut file).map_err(ser_err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CubeCircuit;
+    use ark_bls12_381::{Bls12_381, Fr};
+    use ark_groth16::{Groth16, Proof, ProvingKey, VerifyingKey};
+    use ark_snark::SNARK;
+    use ark_std::rand::thread_rng;
+
+    #[test]
+    fn proof_round_trips_and_verifies() {
+        let x = Fr::from(3u64);
+        let y = x * x * x + x + Fr::from(5u64);
+        let circuit = CubeCircuit { x, y };
+
+        let mut rng = thread_rng();
+        let (pk, vk): (ProvingKey<Bls12_381>, VerifyingKey<Bls12_381>) =
+            Groth16::<Bls12_381>::circuit_specific_setup(circuit.clone(), &mut rng).unwrap();
+        let proof = Groth16::<Bls12_381>::prove(&pk, circuit, &mut rng).unwrap();
+        assert!(Groth16::<Bls12_381>::verify(&vk, &[y], &proof).unwrap());
+
+        let dir = std::env::temp_dir();
+        let vk_path = dir.join("arkworks_tutorial_vk.bin");
+        let proof_path = dir.join("arkworks_tutorial_proof.bin");
+        write_to_file(&vk, &vk_path).unwrap();
+        write_to_file(&proof, &proof_path).unwrap();
+
+        let vk2: VerifyingKey<Bls12_381> = read_from_file(&vk_path).unwrap();
+        let proof2: Proof<Bls12_381> = read_from_file(&proof_path).unwrap();
+
+        // the deserialized proof verifies identically to the in-memory one
+        assert!(Groth16::<Bls12_381>::verify(&vk2, &[y], &proof2).unwrap());
+
+        std::fs::remove_file(vk_path).ok();
+        std::fs::remove_file(proof_path).ok();
+    }
+}
@@ -12,6 +12,22 @@ use ark_bls12_381::Bls12_381;
 use ark_std::rand::thread_rng;
 use std::time::Instant;
 
+mod folding;
+use folding::R1CS;
+mod frontend;
+use frontend::{CubicFCircuit, CustomFCircuit, FCircuit, StepCircuit};
+mod circom;
+use circom::CircomCircuit;
+mod pedersen;
+use pedersen::{Pedersen, PedersenOpeningCircuit};
+mod io;
+use io::{read_from_file, write_to_file};
+
+const PK_PATH: &str = "pk.bin";
+const VK_PATH: &str = "vk.bin";
+const PROOF_PATH: &str = "proof.bin";
+const Y_PATH: &str = "y.bin";
+
 #[derive(Clone, Debug)]
 pub struct CubeCircuit<F: PrimeField> {
     pub x: F, // private input (witness)
@@ -38,6 +54,23 @@ impl<F: PrimeField> ConstraintSynthesizer<F> for CubeCircuit<F> {
 
 fn main() {
     unsafe { env::set_var("RUST_BACKTRACE", "1"); }
+
+    // `setup`, `prove`, and `verify` split the one-shot pipeline into stages
+    // that pass keys and the proof through disk; with no subcommand we fall
+    // back to the full in-memory tutorial walk-through below.
+    let args: Vec<String> = env::args().collect();
+    if args.len() > 1 {
+        match args[1].as_str() {
+            "setup" => return setup_cmd(),
+            "prove" => return prove_cmd(&args),
+            "verify" => return verify_cmd(),
+            other => {
+                eprintln!("unknown subcommand: {} (expected setup|prove|verify)", other);
+                return;
+            }
+        }
+    }
+
     let x_value = Fr::from(3u64);
     // y = x^3 + x + 5
     // 3^3 + 3 + 5 = 9*3 + 3 + 5 = 35
@@ -116,6 +149,37 @@ fn main() {
     // B: [(1, 0)] => 1*z[0] = 1*1 = 1
     // C: [] => 0
 
+    // fold two satisfying instances of the cube circuit into a single relaxed
+    // R1CS instance, Nova style, and check the relaxed relation still holds
+    let r1cs = R1CS::new(a.clone(), b.clone(), c.clone());
+    assert!(r1cs.check_relation(&z));
+    let relaxed1 = r1cs.relax(z.clone());
+    let relaxed2 = r1cs.relax(z.clone());
+    let folded = relaxed1.fold(&relaxed2, Fr::from(42u64));
+    println!(
+        "\nFolded relaxed R1CS satisfies hadamard(Az, Bz) == u*Cz + E: {}",
+        folded.check_relation()
+    );
+
+    // Pedersen-commit to the witness part of z and prove knowledge of the
+    // opening in a second circuit
+    let pedersen = Pedersen::setup(witness.len());
+    let blinding = Fr::from(7u64);
+    let commitment = pedersen.commit(witness, blinding);
+    let opening = PedersenOpeningCircuit {
+        generators: pedersen.generators.clone(),
+        h: pedersen.h,
+        witness: witness.to_vec(),
+        blinding,
+        commitment,
+    };
+    let opening_cs = ConstraintSystem::new_ref();
+    opening.clone().generate_constraints(opening_cs.clone()).unwrap();
+    println!(
+        "\nPedersen opening circuit is satisfied: {}",
+        opening_cs.is_satisfied().unwrap()
+    );
+
     // setup
     let mut rng = thread_rng();
     let (pk, vk): (ProvingKey<Bls12_381>, VerifyingKey<Bls12_381>) = 
@@ -133,4 +197,131 @@ fn main() {
     let verify_time = verify_start.elapsed();
     println!("Proof verification result: {} in {:?}", valid, verify_time);
 
+    // prove one step of the cubic state transition z_1 = z_0^3 + z_0 + 5
+    {
+        let f = CubicFCircuit;
+        let z_0 = vec![Fr::from(3u64)];
+        assert_eq!(z_0.len(), FCircuit::<Fr>::state_len(&f));
+        let z_1 = vec![z_0[0] * z_0[0] * z_0[0] + z_0[0] + Fr::from(5u64)];
+        let step = StepCircuit { f, z_0: z_0.clone(), z_1: z_1.clone() };
+
+        let (pk, vk): (ProvingKey<Bls12_381>, VerifyingKey<Bls12_381>) =
+            Groth16::<Bls12_381>::circuit_specific_setup(step.clone(), &mut rng).unwrap();
+        let proof = Groth16::<Bls12_381>::prove(&pk, step, &mut rng).unwrap();
+        let public_input: Vec<Fr> = z_0.iter().chain(z_1.iter()).cloned().collect();
+        let valid = Groth16::<Bls12_381>::verify(&vk, &public_input, &proof).unwrap();
+        println!("\nCubicFCircuit step proof verifies: {}", valid);
+    }
+
+    // benchmark Groth16 across a few CustomFCircuit sizes
+    println!("\nGroth16 timings for CustomFCircuit (n squaring constraints):");
+    println!("{:>10} | {:>12} | {:>12} | {:>12}", "n", "setup", "prove", "verify");
+    for n in [10usize, 100, 1000, 10000] {
+        let f = CustomFCircuit::new(n);
+        let z_0 = vec![Fr::from(3u64)];
+        assert_eq!(z_0.len(), FCircuit::<Fr>::state_len(&f));
+
+        // z_1 = z_0^(2^n): the result of n repeated squarings
+        let mut z_1 = z_0.clone();
+        for _ in 0..n {
+            z_1[0] = z_1[0] * z_1[0];
+        }
+        let step = StepCircuit { f, z_0: z_0.clone(), z_1: z_1.clone() };
+
+        let setup_start = Instant::now();
+        let (pk, vk): (ProvingKey<Bls12_381>, VerifyingKey<Bls12_381>) =
+            Groth16::<Bls12_381>::circuit_specific_setup(step.clone(), &mut rng).unwrap();
+        let setup_time = setup_start.elapsed();
+
+        let prove_start = Instant::now();
+        let proof = Groth16::<Bls12_381>::prove(&pk, step.clone(), &mut rng).unwrap();
+        let prove_time = prove_start.elapsed();
+
+        let public_input: Vec<Fr> = z_0.iter().chain(z_1.iter()).cloned().collect();
+        let verify_start = Instant::now();
+        let valid = Groth16::<Bls12_381>::verify(&vk, &public_input, &proof).unwrap();
+        let verify_time = verify_start.elapsed();
+        assert!(valid);
+
+        println!(
+            "{:>10} | {:>12?} | {:>12?} | {:>12?}",
+            n, setup_time, prove_time, verify_time
+        );
+    }
+
+    // if a circom-compiled circuit is pointed at via env vars, import its
+    // matrices and witness directly and run the same Groth16 pipeline on them
+    if let (Ok(r1cs_path), Ok(wtns_path)) =
+        (env::var("CIRCOM_R1CS"), env::var("CIRCOM_WTNS"))
+    {
+        prove_circom(&r1cs_path, &wtns_path, &mut rng);
+    }
+}
+
+// build the default cube circuit for the given private input x
+fn cube_circuit(x: Fr) -> CubeCircuit<Fr> {
+    let y = x * x * x + x + Fr::from(5u64);
+    CubeCircuit { x, y }
+}
+
+// `setup`: generate the proving/verifying keys and write both to disk
+fn setup_cmd() {
+    let mut rng = thread_rng();
+    let circuit = cube_circuit(Fr::from(3u64));
+    let (pk, vk): (ProvingKey<Bls12_381>, VerifyingKey<Bls12_381>) =
+        Groth16::<Bls12_381>::circuit_specific_setup(circuit, &mut rng).unwrap();
+    write_to_file(&pk, PK_PATH).unwrap();
+    write_to_file(&vk, VK_PATH).unwrap();
+    println!("Wrote proving key to {} and verifying key to {}", PK_PATH, VK_PATH);
+}
+
+// `prove [x]`: load the proving key, prove, and write the proof and public y
+fn prove_cmd(args: &[String]) {
+    let x = args
+        .get(2)
+        .and_then(|s| s.parse::<u64>().ok())
+        .map(Fr::from)
+        .unwrap_or_else(|| Fr::from(3u64));
+    let circuit = cube_circuit(x);
+    let y = circuit.y;
+
+    let pk: ProvingKey<Bls12_381> = read_from_file(PK_PATH).unwrap();
+    let mut rng = thread_rng();
+    let proof = Groth16::<Bls12_381>::prove(&pk, circuit, &mut rng).unwrap();
+    write_to_file(&proof, PROOF_PATH).unwrap();
+    write_to_file(&y, Y_PATH).unwrap();
+    println!("Wrote proof to {} and public input to {}", PROOF_PATH, Y_PATH);
+}
+
+// `verify`: load only the verifying key, proof, and public y, then verify
+fn verify_cmd() {
+    let vk: VerifyingKey<Bls12_381> = read_from_file(VK_PATH).unwrap();
+    let proof: Proof<Bls12_381> = read_from_file(PROOF_PATH).unwrap();
+    let y: Fr = read_from_file(Y_PATH).unwrap();
+    let valid = Groth16::<Bls12_381>::verify(&vk, &[y], &proof).unwrap();
+    println!("Proof verification result: {}", valid);
+}
+
+// import a circom `.r1cs`/`.wtns` pair and take it through setup/prove/verify
+fn prove_circom<R: ark_std::rand::RngCore + ark_std::rand::CryptoRng>(
+    r1cs_path: &str,
+    wtns_path: &str,
+    rng: &mut R,
+) {
+    let r1cs = circom::read_r1cs(std::fs::File::open(r1cs_path).unwrap()).unwrap();
+    let witness = circom::read_wtns(std::fs::File::open(wtns_path).unwrap()).unwrap();
+    let circuit = CircomCircuit { r1cs, witness };
+    let public_input = circuit.public_inputs();
+
+    // build the A/B/C matrices directly from the parsed constraint list
+    let matrices = circuit.r1cs.to_matrices();
+    println!(
+        "\nImported circom circuit with {} constraints ({} instance, {} witness variables)",
+        matrices.num_constraints, matrices.num_instance_variables, matrices.num_witness_variables
+    );
+    let (pk, vk): (ProvingKey<Bls12_381>, VerifyingKey<Bls12_381>) =
+        Groth16::<Bls12_381>::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = Groth16::<Bls12_381>::prove(&pk, circuit, rng).unwrap();
+    let valid = Groth16::<Bls12_381>::verify(&vk, &public_input, &proof).unwrap();
+    println!("Imported circom proof verification result: {}", valid);
 }
\ No newline at end of file
@@ -0,0 +1,165 @@
+use ark_bls12_381::Fr;
+use ark_ec::{CurveGroup, PrimeGroup};
+use ark_ed_on_bls12_381::{constraints::EdwardsVar, EdwardsProjective};
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    alloc::AllocVar, eq::EqGadget, fields::fp::FpVar, groups::CurveVar,
+    prelude::ToBitsGadget,
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+// We commit with the twisted-Edwards curve "ed-on-bls12-381" (Jubjub). Its
+// base field equals BLS12-381's scalar field `Fr`, so the in-circuit group
+// variables live in the same R1CS field as the witness scalars and no
+// nonnative-field emulation is needed. The witness entries are `Fr` values;
+// we treat their integer representation as the scalar acting on each point.
+type Point = EdwardsProjective;
+
+/// Fixed-base scalar multiplication: the `Fr` value's integer representation
+/// times `base`, matching the in-circuit `scalar_mul_le` over the bits of the
+/// same value.
+pub fn scalar_mul(base: Point, scalar: Fr) -> Point {
+    base.mul_bigint(scalar.into_bigint())
+}
+
+/// Fixed-base multi-scalar multiplication `sum_i scalars[i] * bases[i]` over
+/// the generator table.
+pub fn msm(bases: &[Point], scalars: &[Fr]) -> Point {
+    bases
+        .iter()
+        .zip(scalars.iter())
+        .map(|(b, s)| scalar_mul(*b, *s))
+        .sum()
+}
+
+/// A Pedersen commitment scheme over the Jubjub group: a table of generators
+/// for the witness vector plus a blinding generator `h`.
+#[derive(Clone, Debug)]
+pub struct Pedersen {
+    pub generators: Vec<Point>,
+    pub h: Point,
+}
+
+impl Pedersen {
+    pub fn new(generators: Vec<Point>, h: Point) -> Self {
+        Self { generators, h }
+    }
+
+    /// A deterministic generator table of length `n` plus the blinding
+    /// generator `h`, built from multiples of the group generator. Good enough
+    /// for tutorials; real systems hash to the curve instead.
+    pub fn setup(n: usize) -> Self {
+        let g = Point::generator();
+        let generators = (0..n).map(|i| scalar_mul(g, Fr::from((i + 2) as u64))).collect();
+        let h = scalar_mul(g, Fr::from((n + 2) as u64));
+        Self::new(generators, h)
+    }
+
+    /// Commit to `witness` with the given `blinding`:
+    /// `C = sum_i witness[i]*G_i + blinding*h`.
+    pub fn commit(&self, witness: &[Fr], blinding: Fr) -> Point {
+        msm(&self.generators, witness) + scalar_mul(self.h, blinding)
+    }
+}
+
+/// In-circuit counterpart of [`Pedersen::commit`]. It does not allocate its
+/// inputs — it operates on already-allocated generator/witness variables so it
+/// can be reused inside any circuit. The bit decompositions and scalar
+/// multiplications it performs do add their own witness variables.
+pub struct PedersenGadget;
+
+impl PedersenGadget {
+    /// Recompute the commitment inside the constraint system and return it as a
+    /// group variable: `C = sum_i witness[i]*G_i + blinding*h`.
+    pub fn commit(
+        generators: &[EdwardsVar],
+        h: &EdwardsVar,
+        witness: &[FpVar<Fr>],
+        blinding: &FpVar<Fr>,
+    ) -> Result<EdwardsVar, SynthesisError> {
+        let mut acc = EdwardsVar::zero();
+        for (g, w) in generators.iter().zip(witness.iter()) {
+            let bits = w.to_bits_le()?;
+            acc += g.scalar_mul_le(bits.iter())?;
+        }
+        let blinding_bits = blinding.to_bits_le()?;
+        acc += h.scalar_mul_le(blinding_bits.iter())?;
+        Ok(acc)
+    }
+}
+
+/// Proves knowledge of an opening `(witness, blinding)` of a public Pedersen
+/// commitment. The generators are baked in as constants, the opening is
+/// witnessed, and the claimed commitment is a public input.
+#[derive(Clone, Debug)]
+pub struct PedersenOpeningCircuit {
+    pub generators: Vec<Point>,
+    pub h: Point,
+    pub witness: Vec<Fr>,
+    pub blinding: Fr,
+    pub commitment: Point,
+}
+
+impl ConstraintSynthesizer<Fr> for PedersenOpeningCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let generators: Vec<EdwardsVar> = self
+            .generators
+            .iter()
+            .map(|g| EdwardsVar::new_constant(cs.clone(), *g))
+            .collect::<Result<_, _>>()?;
+        let h = EdwardsVar::new_constant(cs.clone(), self.h)?;
+
+        let witness: Vec<FpVar<Fr>> = self
+            .witness
+            .iter()
+            .map(|w| FpVar::new_witness(cs.clone(), || Ok(*w)))
+            .collect::<Result<_, _>>()?;
+        let blinding = FpVar::new_witness(cs.clone(), || Ok(self.blinding))?;
+
+        let claimed = EdwardsVar::new_input(cs.clone(), || Ok(self.commitment))?;
+
+        let computed = PedersenGadget::commit(&generators, &h, &witness, &blinding)?;
+        computed.enforce_equal(&claimed)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_relations::r1cs::ConstraintSystem;
+
+    #[test]
+    fn correct_opening_satisfies_and_tampered_fails() {
+        let witness = vec![Fr::from(3u64), Fr::from(9u64), Fr::from(27u64)];
+        let blinding = Fr::from(7u64);
+        let pedersen = Pedersen::setup(witness.len());
+        let commitment = pedersen.commit(&witness, blinding);
+
+        // a correct opening satisfies the constraints
+        let circuit = PedersenOpeningCircuit {
+            generators: pedersen.generators.clone(),
+            h: pedersen.h,
+            witness: witness.clone(),
+            blinding,
+            commitment,
+        };
+        let cs = ConstraintSystem::new_ref();
+        circuit.generate_constraints(cs.clone()).unwrap();
+        assert!(cs.is_satisfied().unwrap());
+
+        // a tampered witness no longer opens to the same commitment
+        let mut tampered = witness.clone();
+        tampered[0] += Fr::from(1u64);
+        let bad = PedersenOpeningCircuit {
+            generators: pedersen.generators.clone(),
+            h: pedersen.h,
+            witness: tampered,
+            blinding,
+            commitment,
+        };
+        let cs = ConstraintSystem::new_ref();
+        bad.generate_constraints(cs.clone()).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+    }
+}